@@ -1,3 +1,12 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::panic::catch_unwind;
+use std::rc::Rc;
+use std::sync::Arc;
+
 use libc::c_int;
 use thiserror::Error;
 
@@ -31,7 +40,7 @@ pub enum GdalError {
     CastToF64Error,
     #[error("OGR method '{method_name}' returned error: '{err:?}'")]
     OgrError {
-        err: OGRErr::Type,
+        err: OgrErrType,
         method_name: &'static str,
     },
     #[error("Unhandled type '{field_type:?}' on OGR method {method_name}")]
@@ -59,6 +68,16 @@ pub enum GdalError {
         to: String,
         msg: Option<String>,
     },
+    #[error(
+        "{} of {total} points failed to transform from '{from}' to '{to}': indices {failed_indices:?}",
+        failed_indices.len()
+    )]
+    PartialCoordinateTransform {
+        from: String,
+        to: String,
+        failed_indices: Vec<usize>,
+        total: usize,
+    },
     #[error("Axis not found for key '{key}' in method '{method_name}'")]
     AxisNotFoundError {
         key: String,
@@ -70,6 +89,84 @@ pub enum GdalError {
     UnlinkMemFile { file_name: String },
     #[error("BadArgument")]
     BadArgument(String),
+    #[error("{context}")]
+    Context {
+        context: String,
+        #[source]
+        source: Box<GdalError>,
+        backtrace: Option<Arc<Backtrace>>,
+    },
+    #[error("Failed to create transformer via '{method_name}': '{msg}'")]
+    TransformerCreationFailed {
+        method_name: &'static str,
+        msg: String,
+    },
+    #[error(
+        "{} of {point_count} ground control point transforms failed: indices {failed_indices:?}",
+        failed_indices.len()
+    )]
+    GcpTransformFailed {
+        point_count: usize,
+        failed_indices: Vec<usize>,
+    },
+    #[error("Failed to reproject image: '{msg}'")]
+    ReprojectImageFailed { msg: String },
+}
+
+/// Captures a [`Backtrace`] for attaching to a [`GdalError::Context`], or
+/// `None` if backtrace capture isn't enabled (e.g. `RUST_BACKTRACE` is unset).
+///
+/// Capturing is cheap to attempt unconditionally: [`Backtrace::capture`]
+/// itself checks the environment and is a near no-op when disabled.
+fn capture_backtrace() -> Option<Arc<Backtrace>> {
+    let backtrace = Backtrace::capture();
+    (backtrace.status() == BacktraceStatus::Captured).then(|| Arc::new(backtrace))
+}
+
+impl GdalError {
+    /// Returns the backtrace captured when this [`GdalError::Context`] was
+    /// created, if backtrace capture was enabled at the time. Every other
+    /// variant carries no backtrace and returns `None`.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            GdalError::Context { backtrace, .. } => backtrace.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Extension trait for attaching human-readable context to a [`Result`],
+/// mirroring the `error_chain`-based `ResultExt` this crate used before
+/// adopting `thiserror`.
+///
+/// ```no_run
+/// # use gdal::errors::{Result, ResultExt};
+/// # fn open_band(n: u32) -> Result<()> { Ok(()) }
+/// # fn example() -> Result<()> {
+/// open_band(3).with_context(|| "while opening band 3 of foo.tif".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait ResultExt<T> {
+    /// Wraps the error variant of `self` in a [`GdalError::Context`]
+    /// describing the operation that was in progress, without discarding the
+    /// underlying error (it remains reachable via [`std::error::Error::source`]).
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String,
+    {
+        self.map_err(|source| GdalError::Context {
+            context: f(),
+            source: Box::new(source),
+            backtrace: capture_backtrace(),
+        })
+    }
 }
 
 /// A wrapper for [`CPLErr::Type`] that reflects it as an enum
@@ -92,3 +189,194 @@ impl From<CPLErr::Type> for CplErrType {
         unsafe { std::mem::transmute(error_type) }
     }
 }
+
+/// A wrapper for [`OGRErr::Type`] that reflects it as an enum, so callers can
+/// `match` on e.g. [`OgrErrType::NonExistingFeature`] instead of comparing
+/// against raw integers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+pub enum OgrErrType {
+    None = 0,
+    NotEnoughData = 1,
+    NotEnoughMemory = 2,
+    UnsupportedGeometryType = 3,
+    UnsupportedOperation = 4,
+    CorruptData = 5,
+    Failure = 6,
+    UnsupportedSrs = 7,
+    InvalidHandle = 8,
+    NonExistingFeature = 9,
+}
+
+impl From<OGRErr::Type> for OgrErrType {
+    fn from(error_type: OGRErr::Type) -> Self {
+        if error_type > 9 {
+            return Self::Failure; // fallback type, should not happen
+        }
+
+        unsafe { std::mem::transmute(error_type) }
+    }
+}
+
+type CplErrorCallback = dyn FnMut(CplErrType, c_int, &str);
+
+thread_local! {
+    /// Rust-side mirror of GDAL's thread-local `CPLPushErrorHandler` /
+    /// `CPLPopErrorHandler` stack, invoked by [`cpl_error_handler_trampoline`].
+    /// This must itself be a stack, not a single slot: nesting
+    /// [`push_cpl_error_handler`] (e.g. two nested `collect_cpl_errors` calls
+    /// on the same thread) pushes and pops GDAL's native handler in a
+    /// matching LIFO order, so the Rust-side callback for each level has to
+    /// be restored on `Drop` rather than merely cleared.
+    static HANDLER_STACK: RefCell<Vec<Box<CplErrorCallback>>> = RefCell::new(Vec::new());
+}
+
+/// `extern "C"` shim matching GDAL's `CPLErrorHandler` signature
+/// (`void (*)(CPLErr, int, const char *)`).
+///
+/// This is the only code in this module that crosses the FFI boundary, so it
+/// must never unwind into GDAL's C call stack. Any panic raised by the
+/// installed callback is caught and swallowed. If the handler stack is
+/// already borrowed (a re-entrant call) or empty, the message is logged via
+/// the `log` crate instead.
+extern "C" fn cpl_error_handler_trampoline(class: CPLErr::Type, err_no: c_int, msg: *const c_char) {
+    let _ = catch_unwind(|| {
+        let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+        let class = CplErrType::from(class);
+
+        HANDLER_STACK.with(|stack| {
+            if let Ok(mut stack) = stack.try_borrow_mut() {
+                if let Some(handler) = stack.last_mut() {
+                    handler(class, err_no, &msg);
+                    return;
+                }
+            }
+            log_cpl_error(class, err_no, &msg);
+        });
+    });
+}
+
+/// Forwards a CPL message to the `log` crate, mapping [`CplErrType`] to the
+/// closest matching log level.
+fn log_cpl_error(class: CplErrType, err_no: c_int, msg: &str) {
+    match class {
+        CplErrType::Debug => log::debug!("CPL[{err_no}]: {msg}"),
+        CplErrType::Warning => log::warn!("CPL[{err_no}]: {msg}"),
+        CplErrType::Failure | CplErrType::Fatal => log::error!("CPL[{err_no}]: {msg}"),
+        CplErrType::None => log::trace!("CPL[{err_no}]: {msg}"),
+    }
+}
+
+/// RAII guard returned by [`push_cpl_error_handler`]. Popping GDAL's error
+/// handler stack (via `CPLPopErrorHandler`) on `Drop` pops the matching entry
+/// off the Rust-side [`HANDLER_STACK`], restoring whatever handler (if any)
+/// was active before this guard was created - even if other guards for this
+/// thread were pushed and dropped in between.
+///
+/// Deliberately `!Send`/`!Sync`: both `HANDLER_STACK` and GDAL's own CPL
+/// handler stack are per-thread, so popping them only makes sense on the
+/// thread that pushed this guard. Sending the guard to another thread and
+/// dropping it there would pop that thread's unrelated handler instead.
+#[must_use = "the handler is popped when this guard is dropped"]
+pub struct CplErrorHandlerGuard {
+    _private: (),
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl Drop for CplErrorHandlerGuard {
+    fn drop(&mut self) {
+        HANDLER_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+        unsafe { gdal_sys::CPLPopErrorHandler() };
+    }
+}
+
+/// Installs `handler` as GDAL's active CPL error handler for the current
+/// thread, returning a guard that restores the previous handler when dropped.
+///
+/// Every CPL error, warning or debug message emitted while the guard is alive
+/// is passed to `handler` instead of (or in addition to, if `handler` chooses
+/// to log it) being dropped after the last one. Nesting calls to this
+/// function on the same thread is safe: each guard pushes onto a stack
+/// mirroring GDAL's own `CPLPushErrorHandler`/`CPLPopErrorHandler` stack, so
+/// dropping an inner guard correctly restores the outer handler rather than
+/// losing it.
+pub fn push_cpl_error_handler<F>(handler: F) -> CplErrorHandlerGuard
+where
+    F: FnMut(CplErrType, c_int, &str) + 'static,
+{
+    HANDLER_STACK.with(|stack| stack.borrow_mut().push(Box::new(handler)));
+    unsafe { gdal_sys::CPLPushErrorHandler(Some(cpl_error_handler_trampoline)) };
+    CplErrorHandlerGuard {
+        _private: (),
+        _not_send_sync: PhantomData,
+    }
+}
+
+/// Runs `f` with a collecting CPL error handler installed, returning both its
+/// result and every warning/error GDAL emitted while it ran.
+///
+/// Unlike [`GdalError::CplError`] built from `CPLGetLastErrorMsg`, which only
+/// ever reflects the *last* message, this captures the full stream -
+/// including non-fatal warnings that GDAL would otherwise silently discard.
+pub fn collect_cpl_errors<T>(f: impl FnOnce() -> T) -> (T, Vec<GdalError>) {
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let collected = Rc::clone(&errors);
+
+    let guard = push_cpl_error_handler(move |class, number, msg| {
+        collected.borrow_mut().push(GdalError::CplError {
+            class: class as CPLErr::Type,
+            number,
+            msg: msg.to_string(),
+        });
+    });
+
+    let result = f();
+    drop(guard);
+
+    (result, Rc::try_unwrap(errors).unwrap().into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ogr_err_type_from_known_code() {
+        assert_eq!(OgrErrType::from(9), OgrErrType::NonExistingFeature);
+        assert_eq!(OgrErrType::from(0), OgrErrType::None);
+    }
+
+    #[test]
+    fn ogr_err_type_from_unknown_code_clamps_to_failure() {
+        assert_eq!(OgrErrType::from(10), OgrErrType::Failure);
+        assert_eq!(OgrErrType::from(255), OgrErrType::Failure);
+    }
+
+    #[test]
+    fn with_context_wraps_error_and_preserves_source() {
+        let result: Result<()> = Err(GdalError::CastToF64Error);
+        let wrapped = result
+            .with_context(|| "while opening band 3".to_string())
+            .unwrap_err();
+
+        match &wrapped {
+            GdalError::Context { context, source, .. } => {
+                assert_eq!(context, "while opening band 3");
+                assert!(matches!(**source, GdalError::CastToF64Error));
+            }
+            other => panic!("expected Context, got {other:?}"),
+        }
+        assert!(std::error::Error::source(&wrapped).is_some());
+    }
+
+    #[test]
+    fn with_context_backtrace_matches_capture_availability() {
+        let result: Result<()> = Err(GdalError::CastToF64Error);
+        let wrapped = result.with_context(|| "ctx".to_string()).unwrap_err();
+
+        let expect_captured = Backtrace::capture().status() == BacktraceStatus::Captured;
+        assert_eq!(wrapped.backtrace().is_some(), expect_captured);
+    }
+}
@@ -0,0 +1,141 @@
+use libc::c_int;
+
+use gdal_sys::OGRCoordinateTransformationH;
+
+use crate::errors::{GdalError, Result};
+
+/// Wraps a GDAL `OGRCoordinateTransformation`, used to convert coordinates
+/// between two spatial reference systems.
+pub struct CoordinateTransform {
+    inner: OGRCoordinateTransformationH,
+    from: String,
+    to: String,
+}
+
+impl CoordinateTransform {
+    /// Takes ownership of a raw `OGRCoordinateTransformationH` (e.g. one
+    /// returned by `OCTNewCoordinateTransformation`). `from`/`to` are kept
+    /// only to label the errors this transform produces.
+    ///
+    /// # Safety
+    /// `inner` must be a valid, non-null handle that this `CoordinateTransform`
+    /// now exclusively owns; it is destroyed on `Drop`.
+    pub unsafe fn from_raw(inner: OGRCoordinateTransformationH, from: String, to: String) -> Self {
+        CoordinateTransform { inner, from, to }
+    }
+
+    /// Transforms `x`/`y`/`z` in place, failing the whole batch if even one
+    /// point falls outside the transform's valid domain.
+    ///
+    /// This calls through to [`Self::transform_coords_checked`] and, unlike
+    /// it, collapses the per-point result: if every point failed this
+    /// propagates its [`GdalError::InvalidCoordinateRange`] as-is, and if
+    /// only some did, it builds the richer [`GdalError::PartialCoordinateTransform`]
+    /// naming which points were responsible.
+    pub fn transform_coords(&self, x: &mut [f64], y: &mut [f64], z: &mut [f64]) -> Result<()> {
+        let success = self.transform_coords_checked(x, y, z)?;
+
+        let failed_indices: Vec<usize> = success
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &ok)| (!ok).then_some(i))
+            .collect();
+
+        if failed_indices.is_empty() {
+            Ok(())
+        } else {
+            Err(GdalError::PartialCoordinateTransform {
+                from: self.from.clone(),
+                to: self.to.clone(),
+                failed_indices,
+                total: success.len(),
+            })
+        }
+    }
+
+    /// Transforms `x`/`y`/`z` in place via `OCTTransformEx`, returning a
+    /// per-point success flag instead of failing the whole batch like
+    /// [`Self::transform_coords`] does.
+    ///
+    /// `OCTTransformEx` (unlike the plain `OCTTransform` used by older
+    /// all-or-nothing reprojection code) fills a `pabSuccess` array, so a
+    /// handful of out-of-domain points among many successful ones don't have
+    /// to fail the whole batch: as long as at least one point transformed,
+    /// this returns `Ok` with that array translated to `bool`, `false`
+    /// entries and all. It only returns
+    /// [`GdalError::InvalidCoordinateRange`] when every point failed, since
+    /// there is then no usable per-point data to report.
+    pub fn transform_coords_checked(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<Vec<bool>> {
+        assert_eq!(x.len(), y.len());
+        assert_eq!(x.len(), z.len());
+
+        let count = x.len();
+        let mut success = vec![0 as c_int; count];
+
+        unsafe {
+            gdal_sys::OCTTransformEx(
+                self.inner,
+                count as c_int,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z.as_mut_ptr(),
+                success.as_mut_ptr(),
+            );
+        }
+
+        bucket_transform_result(success, &self.from, &self.to)
+    }
+}
+
+impl Drop for CoordinateTransform {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OCTDestroyCoordinateTransformation(self.inner) };
+    }
+}
+
+/// Turns the raw `pabSuccess` array filled by `OCTTransformEx` into the
+/// [`Result`] that [`CoordinateTransform::transform_coords_checked`] returns.
+/// Split out from its caller so the all-fail/partial-fail/all-succeed
+/// bucketing can be unit tested without a live GDAL transform handle.
+fn bucket_transform_result(success: Vec<c_int>, from: &str, to: &str) -> Result<Vec<bool>> {
+    let count = success.len();
+    let success: Vec<bool> = success.into_iter().map(|ok| ok != 0).collect();
+
+    if count > 0 && success.iter().all(|&ok| !ok) {
+        Err(GdalError::InvalidCoordinateRange {
+            from: from.to_string(),
+            to: to.to_string(),
+            msg: None,
+        })
+    } else {
+        Ok(success)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_points_succeed() {
+        let result = bucket_transform_result(vec![1, 1, 1], "EPSG:4326", "EPSG:3857");
+        assert_eq!(result.unwrap(), vec![true, true, true]);
+    }
+
+    #[test]
+    fn all_points_fail() {
+        let err = bucket_transform_result(vec![0, 0], "EPSG:4326", "EPSG:3857").unwrap_err();
+        assert!(matches!(err, GdalError::InvalidCoordinateRange { .. }));
+    }
+
+    #[test]
+    fn some_points_fail() {
+        let result = bucket_transform_result(vec![1, 0, 1, 0], "EPSG:4326", "EPSG:3857");
+        assert_eq!(result.unwrap(), vec![true, false, true, false]);
+    }
+}